@@ -1,16 +1,172 @@
+// `std::simd` (portable SIMD) is still nightly-only, so the feature gate is scoped to the
+// optional `simd` Cargo feature; the rest of the crate keeps building on stable.
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
 /// A simple library for basic arithmetic operations
 /// This is an example project for testing Suitey's Rust/Cargo detection
 
-pub fn add(a: i32, b: i32) -> i32 {
+use std::ops::{Add, Mul, Rem};
+#[cfg(feature = "simd")]
+use std::simd::{Simd, SimdElement};
+
+/// Bound shared by the generic arithmetic helpers below, implemented for the
+/// fixed-width integer types `i8` through `i128` and `u8` through `u128`
+/// (not for `isize`/`usize`).
+///
+/// `From<u8>` doesn't work here since it excludes `i8` (u8's range doesn't fit
+/// in `i8`), so the identities the helpers need are supplied directly instead.
+pub trait Num: Add<Output = Self> + Mul<Output = Self> + Rem<Output = Self> + PartialEq + Copy {
+    /// The additive identity, `0`.
+    fn zero() -> Self;
+    /// The multiplicative identity, `1`.
+    fn one() -> Self;
+    /// The value `2`, used by [`is_even`].
+    fn two() -> Self;
+}
+
+macro_rules! impl_num {
+    ($($t:ty),*) => {
+        $(
+            impl Num for $t {
+                fn zero() -> Self { 0 }
+                fn one() -> Self { 1 }
+                fn two() -> Self { 2 }
+            }
+        )*
+    };
+}
+
+impl_num!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+pub fn add<T: Num>(a: T, b: T) -> T {
     a + b
 }
 
-pub fn multiply(a: i32, b: i32) -> i32 {
+pub fn multiply<T: Num>(a: T, b: T) -> T {
     a * b
 }
 
-pub fn is_even(n: i32) -> bool {
-    n % 2 == 0
+pub fn is_even<T: Num>(n: T) -> bool {
+    n % T::two() == T::zero()
+}
+
+pub fn sub(a: i32, b: i32) -> i32 {
+    a - b
+}
+
+/// Divides `a` by `b`.
+///
+/// # Panics
+///
+/// Panics if `b` is zero. Use [`checked_div`] if the divisor may be zero.
+pub fn div(a: i32, b: i32) -> i32 {
+    a / b
+}
+
+/// Divides `a` by `b`, returning `None` instead of panicking when `b` is zero.
+pub fn checked_div(a: i32, b: i32) -> Option<i32> {
+    if b == 0 {
+        None
+    } else {
+        Some(a / b)
+    }
+}
+
+/// Adds `a` and `b`, returning `None` instead of wrapping/panicking on overflow.
+pub fn checked_add(a: i32, b: i32) -> Option<i32> {
+    a.checked_add(b)
+}
+
+/// Multiplies `a` and `b`, returning `None` instead of wrapping/panicking on overflow.
+pub fn checked_mul(a: i32, b: i32) -> Option<i32> {
+    a.checked_mul(b)
+}
+
+/// Subtracts `b` from `a`, returning `None` instead of wrapping/panicking on overflow.
+pub fn checked_sub(a: i32, b: i32) -> Option<i32> {
+    a.checked_sub(b)
+}
+
+/// Adds `a` and `b`, clamping to `i32::MIN`/`i32::MAX` on overflow instead of wrapping/panicking.
+pub fn saturating_add(a: i32, b: i32) -> i32 {
+    a.saturating_add(b)
+}
+
+/// Multiplies `a` and `b`, clamping to `i32::MIN`/`i32::MAX` on overflow instead of wrapping/panicking.
+pub fn saturating_mul(a: i32, b: i32) -> i32 {
+    a.saturating_mul(b)
+}
+
+/// Folds `values` into their sum, starting from zero.
+pub fn sum<T: Num>(values: &[T]) -> T {
+    values.iter().fold(T::zero(), |acc, &x| acc + x)
+}
+
+/// Folds `values` into their product, starting from one.
+pub fn product<T: Num>(values: &[T]) -> T {
+    values.iter().fold(T::one(), |acc, &x| acc * x)
+}
+
+/// Sums `values`, short-circuiting to `None` on overflow.
+pub fn checked_sum(values: &[i32]) -> Option<i32> {
+    values.iter().try_fold(0i32, |acc, &x| acc.checked_add(x))
+}
+
+/// Multiplies `values`, short-circuiting to `None` on overflow.
+pub fn checked_product(values: &[i32]) -> Option<i32> {
+    values.iter().try_fold(1i32, |acc, &x| acc.checked_mul(x))
+}
+
+/// Adds `a` and `b` element-wise into `out`, processing `LANES` lanes at a time
+/// (e.g. `add_slices::<f64, 4>` for `f64x4`, `add_slices::<i32, 8>` for `i32x8`)
+/// with a scalar fallback for the remainder tail. Panics if the slice lengths differ.
+///
+/// Requires the `simd` Cargo feature (and a nightly toolchain) since it's built on
+/// `std::simd`, which is not yet stable.
+#[cfg(feature = "simd")]
+pub fn add_slices<T, const LANES: usize>(a: &[T], b: &[T], out: &mut [T])
+where
+    T: SimdElement + Add<Output = T>,
+    Simd<T, LANES>: Add<Output = Simd<T, LANES>>,
+{
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), out.len());
+
+    let chunks = a.len() / LANES;
+    for i in 0..chunks {
+        let start = i * LANES;
+        let va = Simd::<T, LANES>::from_slice(&a[start..start + LANES]);
+        let vb = Simd::<T, LANES>::from_slice(&b[start..start + LANES]);
+        (va + vb).copy_to_slice(&mut out[start..start + LANES]);
+    }
+    for i in chunks * LANES..a.len() {
+        out[i] = a[i] + b[i];
+    }
+}
+
+/// Multiplies `a` and `b` element-wise into `out`, processing `LANES` lanes at a time
+/// with a scalar fallback for the remainder tail. Panics if the slice lengths differ.
+///
+/// Requires the `simd` Cargo feature (and a nightly toolchain); see [`add_slices`].
+#[cfg(feature = "simd")]
+pub fn mul_slices<T, const LANES: usize>(a: &[T], b: &[T], out: &mut [T])
+where
+    T: SimdElement + Mul<Output = T>,
+    Simd<T, LANES>: Mul<Output = Simd<T, LANES>>,
+{
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), out.len());
+
+    let chunks = a.len() / LANES;
+    for i in 0..chunks {
+        let start = i * LANES;
+        let va = Simd::<T, LANES>::from_slice(&a[start..start + LANES]);
+        let vb = Simd::<T, LANES>::from_slice(&b[start..start + LANES]);
+        (va * vb).copy_to_slice(&mut out[start..start + LANES]);
+    }
+    for i in chunks * LANES..a.len() {
+        out[i] = a[i] * b[i];
+    }
 }
 
 #[cfg(test)]
@@ -39,4 +195,139 @@ mod tests {
         assert!(!is_even(1));
         assert!(!is_even(-1));
     }
+
+    #[test]
+    fn test_add_multiply_is_even_i8() {
+        assert_eq!(add(100i8, 27i8), 127i8);
+        assert_eq!(multiply(4i8, 3i8), 12i8);
+        assert!(is_even(4i8));
+        assert!(!is_even(3i8));
+    }
+
+    #[test]
+    fn test_add_i128() {
+        assert_eq!(add(0x1_0000_0000_0000_0000i128, 0x1i128), 0x1_0000_0000_0000_0001i128);
+    }
+
+    #[test]
+    fn test_multiply_i128() {
+        assert_eq!(multiply(0x1_0000_0000i128, 0x1_0000_0000i128), 0x1_0000_0000_0000_0000i128);
+    }
+
+    #[test]
+    fn test_is_even_i128() {
+        assert!(is_even(0x1_0000_0000_0000_0000i128));
+        assert!(!is_even(0x1_0000_0000_0000_0001i128));
+    }
+
+    #[test]
+    fn test_sub() {
+        assert_eq!(sub(5, 3), 2);
+        assert_eq!(sub(3, 5), -2);
+        assert_eq!(sub(0, 0), 0);
+    }
+
+    #[test]
+    fn test_div() {
+        assert_eq!(div(6, 3), 2);
+        assert_eq!(div(7, 2), 3);
+        assert_eq!(div(-6, 3), -2);
+    }
+
+    #[test]
+    fn test_checked_div() {
+        assert_eq!(checked_div(6, 3), Some(2));
+        assert_eq!(checked_div(7, 0), None);
+        assert_eq!(checked_div(-6, 3), Some(-2));
+    }
+
+    #[test]
+    fn test_checked_add() {
+        assert_eq!(checked_add(2, 3), Some(5));
+        assert_eq!(checked_add(i32::MAX, 1), None);
+        assert_eq!(checked_add(i32::MIN, -1), None);
+    }
+
+    #[test]
+    fn test_checked_mul() {
+        assert_eq!(checked_mul(2, 3), Some(6));
+        assert_eq!(checked_mul(i32::MAX, 2), None);
+        assert_eq!(checked_mul(i32::MIN, -1), None);
+    }
+
+    #[test]
+    fn test_checked_sub() {
+        assert_eq!(checked_sub(5, 3), Some(2));
+        assert_eq!(checked_sub(i32::MIN, 1), None);
+        assert_eq!(checked_sub(i32::MAX, -1), None);
+    }
+
+    #[test]
+    fn test_saturating_add() {
+        assert_eq!(saturating_add(2, 3), 5);
+        assert_eq!(saturating_add(i32::MAX, 1), i32::MAX);
+        assert_eq!(saturating_add(i32::MIN, -1), i32::MIN);
+    }
+
+    #[test]
+    fn test_saturating_mul() {
+        assert_eq!(saturating_mul(2, 3), 6);
+        assert_eq!(saturating_mul(i32::MAX, 2), i32::MAX);
+        assert_eq!(saturating_mul(i32::MIN, -1), i32::MAX);
+    }
+
+    #[test]
+    fn test_sum() {
+        assert_eq!(sum(&[1, 2, 3]), 6);
+        assert_eq!(sum::<i32>(&[]), 0);
+        assert_eq!(sum(&[0x1i128, 0x1_0000_0000_0000_0000i128]), 0x1_0000_0000_0000_0001i128);
+    }
+
+    #[test]
+    fn test_sum_slice_ref() {
+        let values = vec![1, 2, 3];
+        assert_eq!(sum(&values), 6);
+    }
+
+    #[test]
+    fn test_product() {
+        assert_eq!(product(&[1, 2, 3, 4]), 24);
+        assert_eq!(product::<i32>(&[]), 1);
+    }
+
+    #[test]
+    fn test_checked_sum() {
+        assert_eq!(checked_sum(&[1, 2, 3]), Some(6));
+        assert_eq!(checked_sum(&[i32::MAX, 1]), None);
+    }
+
+    #[test]
+    fn test_checked_product() {
+        assert_eq!(checked_product(&[2, 3, 4]), Some(24));
+        assert_eq!(checked_product(&[i32::MAX, 2]), None);
+    }
+
+    #[test]
+    #[cfg(feature = "simd")]
+    fn test_add_slices_f64() {
+        let a: Vec<f64> = (0..11).map(|n| n as f64).collect();
+        let b: Vec<f64> = (0..11).map(|n| (n * 2) as f64).collect();
+        let mut out = vec![0.0; a.len()];
+        add_slices::<f64, 4>(&a, &b, &mut out);
+
+        let expected: Vec<f64> = a.iter().zip(&b).map(|(x, y)| x + y).collect();
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "simd")]
+    fn test_mul_slices_i32() {
+        let a: Vec<i32> = (0..19).collect();
+        let b: Vec<i32> = (0..19).map(|n| n + 1).collect();
+        let mut out = vec![0; a.len()];
+        mul_slices::<i32, 8>(&a, &b, &mut out);
+
+        let expected: Vec<i32> = a.iter().zip(&b).map(|(x, y)| x * y).collect();
+        assert_eq!(out, expected);
+    }
 }